@@ -39,6 +39,8 @@
 // We make sure this pallet uses `no_std` for compiling to Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -60,6 +62,12 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// Storage migrations that a runtime can schedule when the pallet's storage layout changes.
+pub mod migrations;
+
+/// The log target used by this pallet.
+pub(crate) const LOG_TARGET: &str = "runtime::template";
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
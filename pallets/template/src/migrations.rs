@@ -0,0 +1,61 @@
+//! Storage migrations for the template pallet.
+//!
+//! Migrations are not run automatically. A runtime opts in by adding them to the `Migrations`
+//! tuple passed to `frame_executive::Executive` in `runtime/src/lib.rs`.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+/// The storage layout this migration operates on.
+///
+/// The item is re-declared through a storage alias so the migration keeps compiling once
+/// [`crate::Something`] is removed from the pallet.
+mod old {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Something<T: Config> = StorageValue<Pallet<T>, u32>;
+}
+
+/// Purges the `Something` storage value.
+///
+/// Run this once, after the `Something` item and the dispatchables using it have been dropped
+/// from the pallet, so a value written on a live chain does not linger in state. Running it
+/// while the pallet still uses `Something` would wipe the stored value.
+///
+/// The migration is idempotent: running it when no value is stored is a no-op apart from the
+/// read.
+pub struct RemoveSomething<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for RemoveSomething<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if old::Something::<T>::exists() {
+			old::Something::<T>::kill();
+			log::info!(target: LOG_TARGET, "Removed the `Something` storage value");
+			T::DbWeight::get().reads_writes(1, 1)
+		} else {
+			log::info!(target: LOG_TARGET, "No `Something` storage value to remove");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		Ok(old::Something::<T>::exists().encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let existed = bool::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode the pre-upgrade state")?;
+		log::info!(target: LOG_TARGET, "`Something` existed before the upgrade: {}", existed);
+		ensure!(!old::Something::<T>::exists(), "`Something` must be removed after the upgrade");
+		Ok(())
+	}
+}
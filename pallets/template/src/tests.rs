@@ -1,5 +1,5 @@
-use crate::{mock::*, Error, Event, Something};
-use frame_support::{assert_noop, assert_ok};
+use crate::{migrations::RemoveSomething, mock::*, Error, Event, Something};
+use frame_support::{assert_noop, assert_ok, traits::OnRuntimeUpgrade};
 
 #[test]
 fn it_works_for_default_value() {
@@ -22,3 +22,18 @@ fn correct_error_for_none_value() {
 		assert_noop!(Template::cause_error(RuntimeOrigin::signed(1)), Error::<Test>::NoneValue);
 	});
 }
+
+#[test]
+fn remove_something_migration_clears_storage() {
+	new_test_ext().execute_with(|| {
+		// Write a value the same way a live chain would have.
+		assert_ok!(Template::do_something(RuntimeOrigin::signed(1), 42));
+		assert_eq!(Something::<Test>::get(), Some(42));
+		// Run the migration and ensure the storage item is gone.
+		RemoveSomething::<Test>::on_runtime_upgrade();
+		assert!(!Something::<Test>::exists());
+		// Running it again on empty storage is a no-op.
+		RemoveSomething::<Test>::on_runtime_upgrade();
+		assert_eq!(Something::<Test>::get(), None);
+	});
+}